@@ -94,24 +94,46 @@ const translations = {
         labelModelName: 'Model Name',
         placeholderModelName: 'e.g., llama3, gpt-4o-mini, mistral',
         submitButton: 'Analyze Profile',
+        validateButton: 'Test connection',
+        validatingText: 'Checking...',
+        githubOk: '✓ GitHub reachable',
+        githubFailed: '✗ GitHub check failed',
+        llmOk: '✓ LLM responded',
+        llmFailed: '✗ LLM check failed',
         loadingTitle: 'Analyzing profile...',
         loadingStatusInitial: 'Fetching GitHub data...',
         loadingStatus1: 'Analyzing repositories and reading source code...',
         loadingStatus2: 'AI is crafting a detailed analysis, this may take a bit...',
         loadingStatus3: 'Still crunching code... large projects need more time.',
         loadingStatus4: 'Almost done... synthesizing insights for all projects.',
+        loadingReposLabel: 'Repositories being analyzed',
         errorTitle: 'Something went wrong',
         errorButtonText: 'Try Again',
         errorMissingUsername: 'Please enter a GitHub username.',
         errorUnknown: 'Unable to reach the server.',
         projectsTitle: 'Projects',
         profileLinkText: 'GitHub Profile',
+        followersLabel: 'followers',
+        statsStar: 'star',
+        statsStars: 'stars',
+        statsFork: 'fork',
+        statsForks: 'forks',
+        statsRepo: 'repo',
+        statsRepos: 'repos',
+        datesCreated: 'Created',
+        datesUpdated: 'Updated',
+        regenerateBioText: '↻ Regenerate bio',
+        regeneratingBioText: 'Regenerating...',
+        regenerateCardTitle: 'Regenerate this card',
         exportHtmlLabel: 'HTML',
         exportJsonLabel: 'JSON',
         exportCsvLabel: 'CSV',
         exportMarkdownLabel: 'Markdown',
+        exportInlineAssetsLabel: 'Inline avatar in HTML export (self-contained file)',
+        labelPreviewImages: '🖼 Show repo preview images',
         backButtonText: '← Start New Analysis',
         useCasesTitle: 'Use Cases',
+        liveDemoLabel: 'Live demo',
         markdownProjectsTitle: 'Projects',
         markdownUseCases: 'Use Cases',
         markdownProblem: 'Problem',
@@ -141,24 +163,46 @@ const translations = {
         labelModelName: 'Model Adı',
         placeholderModelName: 'örn: llama3, gpt-4o-mini, mistral',
         submitButton: 'Profili Analiz Et',
+        validateButton: 'Bağlantıyı test et',
+        validatingText: 'Kontrol ediliyor...',
+        githubOk: '✓ GitHub erişilebilir',
+        githubFailed: '✗ GitHub kontrolü başarısız',
+        llmOk: '✓ LLM yanıt verdi',
+        llmFailed: '✗ LLM kontrolü başarısız',
         loadingTitle: 'Profil analiz ediliyor...',
         loadingStatusInitial: 'GitHub verileri çekiliyor...',
         loadingStatus1: 'Repolar analiz ediliyor, kaynak kodlar okunuyor...',
         loadingStatus2: 'AI detaylı analiz yapıyor, bu biraz sürebilir...',
         loadingStatus3: 'Kod analizi devam ediyor... Büyük projeler daha uzun sürer.',
         loadingStatus4: 'Neredeyse bitti... AI tüm projeleri inceliyor.',
+        loadingReposLabel: 'Analiz edilen repolar',
         errorTitle: 'Bir hata oluştu',
         errorButtonText: 'Tekrar Dene',
         errorMissingUsername: 'Lütfen GitHub kullanıcı adını girin.',
         errorUnknown: 'Sunucuya bağlanılamadı.',
         projectsTitle: 'Projeler',
         profileLinkText: 'GitHub Profili',
+        followersLabel: 'takipçi',
+        statsStar: 'yıldız',
+        statsStars: 'yıldız',
+        statsFork: 'fork',
+        statsForks: 'fork',
+        statsRepo: 'depo',
+        statsRepos: 'depo',
+        datesCreated: 'Oluşturuldu',
+        datesUpdated: 'Güncellendi',
+        regenerateBioText: '↻ Biyografiyi yenile',
+        regeneratingBioText: 'Yenileniyor...',
+        regenerateCardTitle: 'Bu kartı yenile',
         exportHtmlLabel: 'HTML',
         exportJsonLabel: 'JSON',
         exportCsvLabel: 'CSV',
         exportMarkdownLabel: 'Markdown',
+        exportInlineAssetsLabel: 'HTML dışa aktarımında avatarı göm (bağımsız dosya)',
+        labelPreviewImages: '🖼 Depo önizleme görsellerini göster',
         backButtonText: '← Yeni Analiz Yap',
         useCasesTitle: 'Kullanım Senaryoları',
+        liveDemoLabel: 'Canlı demo',
         markdownProjectsTitle: 'Projeler',
         markdownUseCases: 'Kullanım Senaryoları',
         markdownProblem: 'Problem',
@@ -211,7 +255,11 @@ function applyLanguage(lang) {
     const exportJsonLabel = document.getElementById('export-json-label');
     const exportCsvLabel = document.getElementById('export-csv-label');
     const exportMdLabel = document.getElementById('export-md-label');
+    const exportInlineLabel = document.getElementById('export-inline-label');
+    const previewImagesLabel = document.getElementById('label-preview-images');
     const backButtonText = document.getElementById('back-button-text');
+    const regenerateBioText = document.getElementById('regenerate-bio-text');
+    const validateBtnText = document.getElementById('validate-btn-text');
 
     if (formHeading) formHeading.textContent = dict.formHeading;
     if (formDescription) formDescription.textContent = dict.formDescription;
@@ -239,7 +287,11 @@ function applyLanguage(lang) {
     if (exportJsonLabel) exportJsonLabel.textContent = dict.exportJsonLabel;
     if (exportCsvLabel) exportCsvLabel.textContent = dict.exportCsvLabel;
     if (exportMdLabel) exportMdLabel.textContent = dict.exportMarkdownLabel;
+    if (exportInlineLabel) exportInlineLabel.textContent = t('exportInlineAssetsLabel');
+    if (previewImagesLabel) previewImagesLabel.textContent = t('labelPreviewImages');
     if (backButtonText) backButtonText.textContent = dict.backButtonText;
+    if (regenerateBioText) regenerateBioText.textContent = t('regenerateBioText');
+    if (validateBtnText) validateBtnText.textContent = t('validateButton');
 }
 
 // ─── Load Config from .env ──────────────────────────────────────────────────
@@ -341,6 +393,90 @@ function updateLoadingStatus(text) {
     loadingStatus.textContent = text;
 }
 
+// WASM mode: list the repos up front while the much slower LLM analysis runs. A failure
+// only leaves the list hidden; the analysis reports its own errors.
+async function showPendingRepos(payload) {
+    const container = document.getElementById('loading-repos');
+    const list = document.getElementById('loading-repos-list');
+    container.classList.add('hidden');
+    list.innerHTML = '';
+    if (typeof window.git2pageWasmFetchRepos !== 'function') return;
+
+    let repos;
+    try {
+        repos = await window.git2pageWasmFetchRepos({
+            github_username: payload.github_username,
+            github_token: payload.github_token,
+        });
+    } catch (error) {
+        console.warn('Repo preview failed:', error);
+        return;
+    }
+    // The analysis may have finished (or failed) first
+    if (loadingSection.classList.contains('hidden') || !Array.isArray(repos) || repos.length === 0) return;
+
+    document.getElementById('loading-repos-label').textContent = t('loadingReposLabel');
+    repos.forEach((repo) => {
+        const item = document.createElement('li');
+        item.className = 'flex justify-between gap-3 px-3 py-2 rounded-lg bg-white/5 border border-white/10';
+        const name = document.createElement('span');
+        name.className = 'truncate text-gray-200';
+        name.textContent = repo.name;
+        const meta = document.createElement('span');
+        meta.className = 'flex-shrink-0 text-gray-500';
+        meta.textContent = [repo.language, `★ ${repo.stargazers_count}`].filter(Boolean).join(' · ');
+        item.append(name, meta);
+        list.appendChild(item);
+    });
+    container.classList.remove('hidden');
+}
+
+// ─── Credential Check ───────────────────────────────────────────────────────
+
+function setFieldStatus(id, ok, text) {
+    const el = document.getElementById(id);
+    if (!el) return;
+    el.textContent = text;
+    el.classList.remove('hidden', 'text-emerald-400', 'text-red-400');
+    el.classList.add(ok ? 'text-emerald-400' : 'text-red-400');
+}
+
+async function validateCredentials() {
+    const btn = document.getElementById('validate-btn');
+    const label = document.getElementById('validate-btn-text');
+    if (btn) btn.disabled = true;
+    if (label) label.textContent = t('validatingText');
+
+    try {
+        const response = await fetch('/validate', {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({
+                github_token: document.getElementById('github_token').value.trim(),
+                api_url: document.getElementById('api_url').value.trim(),
+                api_key: document.getElementById('api_key').value.trim(),
+                model_name: document.getElementById('model_name').value.trim(),
+            }),
+        });
+        const data = await response.json();
+        const scopes = (data.github_scopes || []).length ? ` (${data.github_scopes.join(', ')})` : '';
+        setFieldStatus('github-status', data.github_ok, data.github_ok ? `${t('githubOk')}${scopes}` : t('githubFailed'));
+        setFieldStatus('llm-status', data.llm_ok, data.llm_ok ? `${t('llmOk')} [${data.detected_mode}]` : t('llmFailed'));
+        if (data.error) console.warn('Credential check:', data.error);
+    } catch (err) {
+        setFieldStatus('llm-status', false, err.message || t('errorUnknown'));
+    } finally {
+        if (btn) btn.disabled = false;
+        if (label) label.textContent = t('validateButton');
+    }
+}
+
+// The credential check needs the backend
+if (wasmModeEnabled()) {
+    const validateBtn = document.getElementById('validate-btn');
+    if (validateBtn) validateBtn.classList.add('hidden');
+}
+
 // ─── Form Handler ───────────────────────────────────────────────────────────
 
 form.addEventListener('submit', async (e) => {
@@ -359,6 +495,8 @@ form.addEventListener('submit', async (e) => {
         api_key: apiKey,
         model_name: modelName,
         language,
+        include_dates: true,
+        with_preview_images: Boolean(document.getElementById('with_preview_images')?.checked),
     };
 
     if (!githubUsername) {
@@ -408,6 +546,7 @@ form.addEventListener('submit', async (e) => {
                     throw new Error(data.error || t('errorUnknown'));
                 }
             } else {
+                showPendingRepos(payload);
                 data = await window.git2pageWasmAnalyze(payload);
             }
         } else {
@@ -457,6 +596,18 @@ function renderResult(data) {
     document.getElementById('hero-title').textContent = data.hero_title;
     document.getElementById('hero-bio').textContent = data.bio;
 
+    // Profile meta (name · location · followers)
+    const metaParts = [];
+    if (data.name) metaParts.push(data.name);
+    if (data.location) metaParts.push(`📍 ${data.location}`);
+    if (data.followers > 0) metaParts.push(`${data.followers} ${t('followersLabel')}`);
+    const heroMeta = document.getElementById('hero-meta');
+    if (heroMeta) heroMeta.textContent = metaParts.join(' · ');
+
+    // Totals banner (absent from WASM-mode results)
+    const heroStats = document.getElementById('hero-stats');
+    if (heroStats) heroStats.textContent = statsLine(data.stats);
+
     // Profile link
     const profileLink = document.getElementById('profile-link');
     profileLink.href = data.profile_url;
@@ -472,9 +623,88 @@ function renderResult(data) {
         grid.appendChild(card);
     });
 
+    // Bio regeneration needs the backend
+    const regenerateBtn = document.getElementById('regenerate-bio-btn');
+    if (regenerateBtn) regenerateBtn.classList.toggle('hidden', wasmModeEnabled());
+
     showSection(resultSection);
 }
 
+async function regenerateBio() {
+    if (!lastResultData) return;
+    const btn = document.getElementById('regenerate-bio-btn');
+    const label = document.getElementById('regenerate-bio-text');
+    if (btn) btn.disabled = true;
+    if (label) label.textContent = t('regeneratingBioText');
+
+    try {
+        const response = await fetch('/analyze/bio', {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({
+                ...lastResultData,
+                api_url: document.getElementById('api_url').value.trim(),
+                api_key: document.getElementById('api_key').value.trim(),
+                model_name: document.getElementById('model_name').value.trim(),
+                language: document.getElementById('language').value,
+            }),
+        });
+        const data = await response.json();
+        if (!response.ok) {
+            throw new Error(data.error || t('errorUnknown'));
+        }
+        lastResultData.hero_title = data.hero_title;
+        lastResultData.bio = data.bio;
+        document.getElementById('hero-title').textContent = data.hero_title;
+        document.getElementById('hero-bio').textContent = data.bio;
+    } catch (err) {
+        alert(err.message || t('errorUnknown'));
+    } finally {
+        if (btn) btn.disabled = false;
+        if (label) label.textContent = t('regenerateBioText');
+    }
+}
+
+// Re-analyzes one repo and swaps its card in place; other cards and the bio stay as they are
+async function regenerateProject(index) {
+    if (!lastResultData || !lastResultData.projects[index]) return;
+    const project = lastResultData.projects[index];
+    const btn = document.querySelector(`[data-regenerate-index="${index}"]`);
+    if (btn) btn.disabled = true;
+
+    try {
+        const response = await fetch('/analyze/repo', {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({
+                github_username: lastResultData.username,
+                repo: project.owner ? `${project.owner}/${project.name}` : project.name,
+                github_token: document.getElementById('github_token').value.trim(),
+                api_url: document.getElementById('api_url').value.trim(),
+                api_key: document.getElementById('api_key').value.trim(),
+                model_name: document.getElementById('model_name').value.trim(),
+                language: document.getElementById('language').value,
+                include_dates: true,
+                with_preview_images: Boolean(project.preview_image_url),
+            }),
+        });
+        const data = await response.json();
+        if (!response.ok) {
+            throw new Error(data.error || t('errorUnknown'));
+        }
+        lastResultData.projects[index] = data;
+        const oldCard = btn && btn.closest('.project-card');
+        if (oldCard) {
+            const card = createProjectCard(data, index);
+            card.style.animationDelay = '0s';
+            oldCard.replaceWith(card);
+        }
+    } catch (err) {
+        alert(err.message || t('errorUnknown'));
+        if (btn) btn.disabled = false;
+    }
+}
+
 function createProjectCard(project, index) {
     const card = document.createElement('div');
     const delay = Math.min(index * 0.08, 0.8);
@@ -515,14 +745,38 @@ function createProjectCard(project, index) {
                     <span class="w-2 h-2 rounded-full ${getLanguageColor(project.language)}"></span>
                     ${project.language}
                 </span>` : ''}
+            ${project.license ? `
+                <span class="px-1.5 py-0.5 border border-white/10 rounded text-[10px] uppercase tracking-wide">⚖ ${escapeHtml(project.license)}</span>` : ''}
+            ${datesLine(project) ? `
+                <span>${escapeHtml(datesLine(project))}</span>` : ''}
         </div>
     `;
 
+    const homepageUrl = safeExternalUrl(project.homepage);
+    const liveDemoHTML = homepageUrl ? `
+        <a href="${escapeAttr(homepageUrl)}" target="_blank" rel="noopener" class="inline-flex items-center gap-1 mb-3 text-xs font-medium text-brand-300 hover:text-brand-200">
+            ↗ ${escapeHtml(t('liveDemoLabel'))}
+        </a>` : '';
+
+    // GitHub's social preview image, with with_preview_images
+    const previewUrl = safeExternalUrl(project.preview_image_url);
+    const previewHTML = previewUrl ? `
+        <img src="${escapeAttr(previewUrl)}" alt="" loading="lazy" class="w-full aspect-[2/1] object-cover rounded-xl mb-4 border border-white/10">` : '';
+
     // Tech stack badges
     const techBadges = project.tech_stack.map(tech =>
         `<span class="px-2.5 py-1 bg-brand-500/15 text-brand-300 text-xs font-medium rounded-lg">${escapeHtml(tech)}</span>`
     ).join('');
 
+    // Org repos merged via include_orgs name their account
+    const ownerHTML = (project.owner && lastResultData && project.owner.toLowerCase() !== lastResultData.username.toLowerCase())
+        ? `<p class="text-xs text-gray-500 -mt-2 mb-2">@${escapeHtml(project.owner)}</p>`
+        : '';
+
+    // Single-card regeneration needs the backend
+    const regenerateHTML = wasmModeEnabled() ? '' : `
+            <button type="button" data-regenerate-index="${index}" onclick="regenerateProject(${index})" title="${escapeAttr(t('regenerateCardTitle'))}" class="text-gray-500 hover:text-brand-400 transition-colors flex-shrink-0 disabled:opacity-40 disabled:animate-pulse">↻</button>`;
+
     const timelinePrefix = currentTemplate === 'timeline'
         ? `<span class="text-xs uppercase tracking-widest text-brand-300">#${String(index + 1).padStart(2, '0')}</span>`
         : '';
@@ -544,19 +798,23 @@ function createProjectCard(project, index) {
     ` : '';
 
     card.innerHTML = `
+        ${previewHTML}
         <div class="flex items-start justify-between mb-3 gap-3">
             <h3 class="text-lg font-bold text-white truncate">${escapeHtml(project.name)}</h3>
             ${timelinePrefix}
-            <a href="${escapeHtml(project.html_url)}" target="_blank" class="text-gray-500 hover:text-brand-400 transition-colors flex-shrink-0 ml-2">
+            ${regenerateHTML}
+            <a href="${escapeAttr(project.html_url)}" target="_blank" class="text-gray-500 hover:text-brand-400 transition-colors flex-shrink-0 ml-2">
                 <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                     <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M10 6H6a2 2 0 00-2 2v10a2 2 0 002 2h10a2 2 0 002-2v-4M14 4h6m0 0v6m0-6L10 14"/>
                 </svg>
             </a>
         </div>
+        ${ownerHTML}
         <p class="text-brand-200 text-sm font-medium mb-2">${escapeHtml(project.problem_solved)}</p>
         <p class="text-gray-400 text-sm leading-relaxed mb-3">${escapeHtml(project.detailed_description || '')}</p>
         ${useCasesHTML}
         <div class="flex flex-wrap gap-2 mb-3">${techBadges}</div>
+        ${liveDemoHTML}
         ${statsHTML}
     `;
 
@@ -565,12 +823,85 @@ function createProjectCard(project, index) {
 
 // ─── Utility Functions ──────────────────────────────────────────────────────
 
+function compactCount(n) {
+    if (n < 1000) return String(n);
+    if (n < 999950) return `${(n / 1000).toFixed(1).replace(/\.0$/, '')}k`;
+    return `${(n / 1000000).toFixed(1).replace(/\.0$/, '')}M`;
+}
+
+// "Created 2021 · Updated 2024" from a card's ISO dates
+function datesLine(project) {
+    const year = (date) => (/^\d{4}/.test(date || '') ? date.slice(0, 4) : '');
+    const parts = [];
+    if (year(project.created_at)) parts.push(`${t('datesCreated')} ${year(project.created_at)}`);
+    if (year(project.pushed_at)) parts.push(`${t('datesUpdated')} ${year(project.pushed_at)}`);
+    return parts.join(' · ');
+}
+
+// "⭐ 1.2k stars · 🍴 80 forks · 40 repos · Rust, Go" from the response's stats, or '' without them
+function statsLine(stats) {
+    if (!stats) return '';
+    const counted = (n, key) => `${compactCount(n)} ${t(n === 1 ? key : `${key}s`)}`;
+    const parts = [
+        `⭐ ${counted(stats.total_stars, 'statsStar')}`,
+        `🍴 ${counted(stats.total_forks, 'statsFork')}`,
+        counted(stats.repo_count, 'statsRepo'),
+    ];
+    const languages = (stats.top_languages || []).slice(0, 3).map(l => l.language);
+    if (languages.length > 0) parts.push(languages.join(', '));
+    return parts.join(' · ');
+}
+
 function escapeHtml(text) {
     const div = document.createElement('div');
     div.textContent = text;
     return div.innerHTML;
 }
 
+// escapeHtml leaves quotes alone, which is fine for text but not inside attribute values
+function escapeAttr(text) {
+    return escapeHtml(text).replace(/"/g, '&quot;').replace(/'/g, '&#39;');
+}
+
+// Page <title> and preview title; mirrors page_title in main.rs
+function pageTitle(d) {
+    const hero = (d.hero_title || '').trim();
+    return hero ? `${hero} - ${d.username}` : `${d.username} - Git2Page`;
+}
+
+// Open Graph / Twitter Card tags; og:image must be a real URL, never the inlined data URI
+function socialMetaTags(d) {
+    const title = pageTitle(d);
+    const bio = d.bio || '';
+    const description = bio.length > 200 ? `${bio.slice(0, 199).trimEnd()}…` : bio;
+    const image = safeExternalUrl(d.avatar_url);
+    const tags = [
+        '<meta property="og:type" content="profile">',
+        `<meta property="og:title" content="${escapeAttr(title)}">`,
+        `<meta property="og:description" content="${escapeAttr(description)}">`,
+        `<meta name="description" content="${escapeAttr(description)}">`,
+        '<meta name="twitter:card" content="summary">',
+        `<meta name="twitter:title" content="${escapeAttr(title)}">`,
+        `<meta name="twitter:description" content="${escapeAttr(description)}">`,
+    ];
+    if (image) {
+        tags.push(`<meta property="og:image" content="${escapeAttr(image)}">`);
+        tags.push(`<meta name="twitter:image" content="${escapeAttr(image)}">`);
+    }
+    return tags.join('\n    ');
+}
+
+function safeExternalUrl(url) {
+    if (!url) return '';
+    const trimmed = String(url).trim();
+    // Quotes, brackets and whitespace have no business in a link and could end an attribute
+    if (/["'<>\s\u0000-\u001f\u007f]/.test(trimmed)) return '';
+    if (/^https?:\/\//i.test(trimmed)) return trimmed;
+    // GitHub allows bare hosts like "example.com" in the homepage field
+    if (/^[a-z0-9.-]+\.[a-z]{2,}(\/.*)?$/i.test(trimmed)) return `https://${trimmed}`;
+    return '';
+}
+
 // ─── Export Functions ───────────────────────────────────────────────────────
 
 function downloadFile(filename, content, mimeType) {
@@ -615,10 +946,13 @@ function exportAsMarkdown() {
     let md = `# ${d.hero_title}\n\n`;
     md += `![Avatar](${d.avatar_url})\n\n`;
     md += `${d.bio}\n\n`;
+    if (d.stats) md += `${statsLine(d.stats)}\n\n`;
     md += `[GitHub Profile](${d.profile_url})\n\n`;
     md += `---\n\n## ${t('markdownProjectsTitle')}\n\n`;
     d.projects.forEach(p => {
         md += `### ${p.name}\n\n`;
+        const previewUrl = safeExternalUrl(p.preview_image_url);
+        if (previewUrl) md += `![${p.name} preview](${previewUrl})\n\n`;
         if (p.problem_solved) md += `**${t('markdownProblem')}:** ${p.problem_solved}\n\n`;
         if (p.detailed_description) md += `${p.detailed_description}\n\n`;
         if (p.use_cases && p.use_cases.length > 0) {
@@ -629,15 +963,40 @@ function exportAsMarkdown() {
         if (p.tech_stack && p.tech_stack.length > 0) {
             md += `**${t('markdownTech')}:** ${p.tech_stack.join(', ')}\n\n`;
         }
-        md += `⭐ ${p.stars} | 🍴 ${p.forks} | ${p.language || 'N/A'} | [Repo](${p.html_url})\n\n---\n\n`;
+        const homepageUrl = safeExternalUrl(p.homepage);
+        const demoLink = homepageUrl ? ` | [${t('liveDemoLabel')}](${homepageUrl})` : '';
+        const license = p.license ? ` | ⚖ ${p.license}` : '';
+        const dates = datesLine(p) ? ` | ${datesLine(p)}` : '';
+        md += `⭐ ${p.stars} | 🍴 ${p.forks} | ${p.language || 'N/A'}${license}${dates} | [Repo](${p.html_url})${demoLink}\n\n---\n\n`;
     });
     md += `${t('markdownGeneratedBy')}\n`;
     downloadFile(`${d.username}-git2page.md`, md, 'text/markdown');
 }
 
-function exportAsHTML() {
+async function fetchAvatarDataUri(d) {
+    // Server mode goes through the /avatar proxy; WASM mode has no backend, so fetch GitHub's CDN directly
+    const src = wasmModeEnabled() ? d.avatar_url : `/avatar/${encodeURIComponent(d.username)}`;
+    try {
+        const resp = await fetch(src);
+        if (!resp.ok) return d.avatar_url;
+        const blob = await resp.blob();
+        return await new Promise((resolve) => {
+            const reader = new FileReader();
+            reader.onload = () => resolve(reader.result);
+            reader.onerror = () => resolve(d.avatar_url);
+            reader.readAsDataURL(blob);
+        });
+    } catch (_) {
+        return d.avatar_url;
+    }
+}
+
+async function exportAsHTML(options = {}) {
     if (!lastResultData) return;
     const d = lastResultData;
+    const inlineToggle = document.getElementById('export-inline-assets');
+    const inlineAssets = options.inline_assets ?? Boolean(inlineToggle && inlineToggle.checked);
+    const avatarSrc = inlineAssets ? await fetchAvatarDataUri(d) : d.avatar_url;
     const exportTheme = getExportTheme(currentTemplate);
     const projectCards = d.projects.map((p, index) => {
         const useCases = (p.use_cases && p.use_cases.length > 0)
@@ -646,20 +1005,32 @@ function exportAsHTML() {
         const techBadges = (p.tech_stack || []).map(t =>
             `<span style="display:inline-block;background:${exportTheme.badgeBg};color:${exportTheme.badgeText};padding:2px 10px;border-radius:8px;font-size:12px;margin:2px">${escapeHtml(t)}</span>`
         ).join('');
+        const homepageUrl = safeExternalUrl(p.homepage);
+        const liveDemo = homepageUrl
+            ? `<a href="${escapeAttr(homepageUrl)}" target="_blank" rel="noopener" style="display:inline-block;margin-top:12px;color:${exportTheme.link};font-size:13px">↗ ${escapeHtml(t('liveDemoLabel'))}</a>`
+            : '';
+        const previewUrl = safeExternalUrl(p.preview_image_url);
+        const preview = previewUrl
+            ? `<img src="${escapeAttr(previewUrl)}" alt="" loading="lazy" style="display:block;width:100%;aspect-ratio:2/1;object-fit:cover;border-radius:10px;margin-bottom:16px">`
+            : '';
         const timelineMark = currentTemplate === 'timeline' ? `<div style="font-size:11px;letter-spacing:0.08em;text-transform:uppercase;color:${exportTheme.muted};margin-bottom:10px">Entry ${index + 1}</div>` : '';
         return `
         <div style="background:${exportTheme.cardBg};border:1px solid ${exportTheme.cardBorder};border-radius:${exportTheme.cardRadius};padding:24px;margin-bottom:16px">
             ${timelineMark}
+            ${preview}
             <div style="display:flex;justify-content:space-between;align-items:center">
                 <h3 style="color:#fff;margin:0;font-size:18px">${escapeHtml(p.name)}</h3>
-                <a href="${escapeHtml(p.html_url)}" target="_blank" style="color:${exportTheme.link};font-size:13px">View →</a>
+                <a href="${escapeAttr(p.html_url)}" target="_blank" style="color:${exportTheme.link};font-size:13px">View →</a>
             </div>
             <p style="color:${exportTheme.accent};font-size:14px;margin-top:8px;font-weight:500">${escapeHtml(p.problem_solved || '')}</p>
             <p style="color:${exportTheme.text};font-size:14px;line-height:1.6">${escapeHtml(p.detailed_description || '')}</p>
             ${useCases}
             <div style="margin-top:12px">${techBadges}</div>
+            ${liveDemo}
             <div style="margin-top:12px;font-size:12px;color:${exportTheme.muted}">
                 ⭐ ${p.stars} &nbsp; 🍴 ${p.forks} &nbsp; ${p.language || ''}
+                ${p.license ? `&nbsp; <span style="border:1px solid ${exportTheme.cardBorder};border-radius:4px;padding:1px 6px;font-size:11px">⚖ ${escapeHtml(p.license)}</span>` : ''}
+                ${datesLine(p) ? `&nbsp; ${escapeHtml(datesLine(p))}` : ''}
             </div>
         </div>`;
     }).join('');
@@ -669,7 +1040,9 @@ function exportAsHTML() {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>${escapeHtml(d.username)} - Git2Page</title>
+    <title>${escapeHtml(pageTitle(d))}</title>
+    ${avatarSrc ? `<link rel="icon" href="${escapeAttr(avatarSrc)}">` : ''}
+    ${socialMetaTags(d)}
     <style>
         * { margin: 0; padding: 0; box-sizing: border-box; }
         body { font-family: ${exportTheme.fontFamily}; background: ${exportTheme.bodyBg}; color: #e5e7eb; padding: 40px 20px; }
@@ -682,10 +1055,11 @@ function exportAsHTML() {
 <body>
     <div class="container">
         <div style="text-align:center;padding:40px 0;border-bottom:1px solid #222">
-            <img src="${d.avatar_url}" alt="avatar" style="width:96px;height:96px;border-radius:50%;border:3px solid #6366f150;margin-bottom:20px"/>
+            <img src="${escapeAttr(avatarSrc)}" alt="avatar" style="width:96px;height:96px;border-radius:50%;border:3px solid #6366f150;margin-bottom:20px"/>
             <h1 style="font-size:36px;background:${exportTheme.heroGradient};-webkit-background-clip:text;-webkit-text-fill-color:transparent">${escapeHtml(d.hero_title)}</h1>
             <p style="color:${exportTheme.text};font-size:16px;max-width:600px;margin:16px auto;line-height:1.6">${escapeHtml(d.bio)}</p>
-            <a href="${d.profile_url}" target="_blank" style="display:inline-block;margin-top:12px;padding:8px 20px;background:${exportTheme.buttonBg};border-radius:12px;font-size:14px">${escapeHtml(t('htmlProfileButton'))}</a>
+            ${d.stats ? `<div style="color:${exportTheme.muted};font-size:13px;margin-bottom:4px">${escapeHtml(statsLine(d.stats))}</div>` : ''}
+            <a href="${escapeAttr(d.profile_url)}" target="_blank" style="display:inline-block;margin-top:12px;padding:8px 20px;background:${exportTheme.buttonBg};border-radius:12px;font-size:14px">${escapeHtml(t('htmlProfileButton'))}</a>
         </div>
         <div style="padding:32px 0">
             <h2 style="font-size:24px;margin-bottom:24px;color:#fff">${escapeHtml(t('htmlProjectsHeading'))}</h2>
@@ -1,6 +1,8 @@
 use gloo_net::http::Request;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
 #[derive(Deserialize)]
@@ -11,9 +13,65 @@ struct AnalyzeInput {
     api_key: String,
     model_name: String,
     language: String,
+    #[serde(default = "default_batch_size")]
+    batch_size: usize,
+    /// GitHub Enterprise (`https://ghe.example.com/api/v3`) or a proxy in front of the API.
+    #[serde(default)]
+    github_api_base: String,
+    /// Sent with every GitHub request, e.g. headers a corporate proxy requires.
+    #[serde(default)]
+    extra_headers: HashMap<String, String>,
+}
+
+fn default_batch_size() -> usize {
+    8
 }
 
 #[derive(Deserialize)]
+struct FetchReposInput {
+    github_username: String,
+    #[serde(default)]
+    github_token: String,
+    #[serde(default)]
+    github_api_base: String,
+    #[serde(default)]
+    extra_headers: HashMap<String, String>,
+}
+
+const DEFAULT_GITHUB_API_BASE: &str = "https://api.github.com";
+
+/// Where and how GitHub requests are sent: API base, token and extra headers.
+struct GitHubApi {
+    base: String,
+    token: String,
+    extra_headers: HashMap<String, String>,
+}
+
+impl GitHubApi {
+    fn new(base: &str, token: &str, extra_headers: HashMap<String, String>) -> Self {
+        let base = base.trim().trim_end_matches('/');
+        GitHubApi {
+            base: if base.is_empty() { DEFAULT_GITHUB_API_BASE } else { base }.to_string(),
+            token: token.trim().to_string(),
+            extra_headers,
+        }
+    }
+
+    fn get(&self, path: &str) -> gloo_net::http::RequestBuilder {
+        let mut req = Request::get(&format!("{}{}", self.base, path))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "git2page-wasm");
+        for (name, value) in &self.extra_headers {
+            req = req.header(name, value);
+        }
+        if !self.token.is_empty() {
+            req = req.header("Authorization", &format!("Bearer {}", self.token));
+        }
+        req
+    }
+}
+
+#[derive(Deserialize, Serialize)]
 struct GitHubRepo {
     name: String,
     description: Option<String>,
@@ -24,6 +82,15 @@ struct GitHubRepo {
     #[serde(default)]
     topics: Vec<String>,
     fork: bool,
+    #[serde(default)]
+    homepage: Option<String>,
+    #[serde(default)]
+    license: Option<GitHubLicense>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct GitHubLicense {
+    spdx_id: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -32,6 +99,12 @@ struct GitHubUser {
     html_url: String,
 }
 
+#[derive(Deserialize)]
+struct GitHubContent {
+    content: Option<String>,
+    encoding: Option<String>,
+}
+
 #[derive(Serialize)]
 struct AnalyzeOutput {
     username: String,
@@ -40,6 +113,7 @@ struct AnalyzeOutput {
     hero_title: String,
     bio: String,
     projects: Vec<ProjectCard>,
+    failed_batches: usize,
 }
 
 #[derive(Serialize, Clone)]
@@ -54,6 +128,8 @@ struct ProjectCard {
     forks: u32,
     html_url: String,
     description: Option<String>,
+    homepage: Option<String>,
+    license: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -67,11 +143,18 @@ struct LlmProject {
 
 #[derive(Deserialize)]
 struct LlmResponse {
+    #[serde(default)]
     hero_title: String,
+    #[serde(default)]
     bio: String,
     projects: Vec<LlmProject>,
 }
 
+#[derive(Deserialize)]
+struct LlmBatchResponse {
+    projects: Vec<LlmProject>,
+}
+
 #[wasm_bindgen]
 pub async fn analyze_profile(payload: JsValue) -> Result<JsValue, JsValue> {
     let input: AnalyzeInput = serde_wasm_bindgen::from_value(payload)
@@ -81,37 +164,81 @@ pub async fn analyze_profile(payload: JsValue) -> Result<JsValue, JsValue> {
         return Err(JsValue::from_str("GitHub username is required"));
     }
 
-    let user = fetch_github_user(&input.github_username, &input.github_token).await?;
-    let repos = fetch_repos(&input.github_username, &input.github_token).await?;
+    let github = GitHubApi::new(&input.github_api_base, &input.github_token, input.extra_headers);
+    let user = fetch_github_user(&github, &input.github_username).await?;
+    let repos = fetch_repos(&github, &input.github_username).await?;
 
     if repos.is_empty() {
         return Err(JsValue::from_str("No public repositories found for this user."));
     }
 
-    let prompt = build_prompt(&input.github_username, &repos, &input.language);
-    let llm = call_llm(
-        &input.api_url,
-        &input.api_key,
-        &input.model_name,
-        &prompt,
-        &input.language,
-    )
-    .await?;
+    let contexts = gather_repo_context(&github, &input.github_username, &repos).await;
+
+    let batch_size = input.batch_size.max(1);
+    let mut all_llm_projects: Vec<LlmProject> = Vec::new();
+    let mut llm_hero_title = String::new();
+    let mut llm_bio = String::new();
+    let mut failed_batches = 0;
+
+    for (batch_idx, chunk_start) in (0..contexts.len()).step_by(batch_size).enumerate() {
+        let chunk_end = std::cmp::min(chunk_start + batch_size, contexts.len());
+        let batch_contexts = &contexts[chunk_start..chunk_end];
+        let batch_names: Vec<String> = repos[chunk_start..chunk_end]
+            .iter()
+            .map(|r| r.name.clone())
+            .collect();
+
+        if batch_idx == 0 {
+            // First batch: hero_title + bio + projects
+            let prompt = build_prompt(
+                &input.github_username,
+                batch_contexts,
+                &input.language,
+                &batch_names,
+            );
+            let llm: LlmResponse = call_llm(
+                &input.api_url,
+                &input.api_key,
+                &input.model_name,
+                &prompt,
+                &input.language,
+            )
+            .await?;
+            llm_hero_title = llm.hero_title;
+            llm_bio = llm.bio;
+            all_llm_projects.extend(llm.projects);
+        } else {
+            // Subsequent batches: projects only; a failed batch falls back to repo metadata
+            let prompt = build_batch_prompt(batch_contexts, &input.language, &batch_names);
+            match call_llm::<LlmBatchResponse>(
+                &input.api_url,
+                &input.api_key,
+                &input.model_name,
+                &prompt,
+                &input.language,
+            )
+            .await
+            {
+                Ok(batch) => all_llm_projects.extend(batch.projects),
+                Err(_) => failed_batches += 1,
+            }
+        }
+    }
 
+    let (no_description, hero_fallback, bio_fallback) = fallback_texts(&input.language);
     let projects = repos
         .iter()
         .map(|repo| {
-            let llm_project = llm
-                .projects
+            let llm_project = all_llm_projects
                 .iter()
-                .find(|p| p.name.eq_ignore_ascii_case(&repo.name));
+                .find(|p| p.name.to_lowercase() == repo.name.to_lowercase());
 
             ProjectCard {
                 name: repo.name.clone(),
                 problem_solved: llm_project
                     .map(|p| p.problem_solved.clone())
                     .or_else(|| repo.description.clone())
-                    .unwrap_or_else(|| "No description available.".to_string()),
+                    .unwrap_or_else(|| no_description.to_string()),
                 detailed_description: llm_project
                     .map(|p| p.detailed_description.clone())
                     .unwrap_or_default(),
@@ -131,20 +258,22 @@ pub async fn analyze_profile(payload: JsValue) -> Result<JsValue, JsValue> {
                 forks: repo.forks_count,
                 html_url: repo.html_url.clone(),
                 description: repo.description.clone(),
+                homepage: repo.homepage.clone(),
+                license: repo_license(repo),
             }
         })
         .collect::<Vec<_>>();
 
-    let hero_title = if llm.hero_title.trim().is_empty() {
-        format!("{} — GitHub Portfolio", input.github_username)
+    let hero_title = if llm_hero_title.trim().is_empty() {
+        hero_fallback.replace("{names}", &input.github_username)
     } else {
-        llm.hero_title
+        llm_hero_title
     };
 
-    let bio = if llm.bio.trim().is_empty() {
-        format!("An AI-curated project portfolio for @{}", input.github_username)
+    let bio = if llm_bio.trim().is_empty() {
+        bio_fallback.replace("{handles}", &format!("@{}", input.github_username))
     } else {
-        llm.bio
+        llm_bio
     };
 
     let output = AnalyzeOutput {
@@ -154,23 +283,32 @@ pub async fn analyze_profile(payload: JsValue) -> Result<JsValue, JsValue> {
         hero_title,
         bio,
         projects,
+        failed_batches,
     };
 
     serde_wasm_bindgen::to_value(&output)
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {e}")))
 }
 
-async fn fetch_github_user(username: &str, token: &str) -> Result<GitHubUser, JsValue> {
-    let url = format!("https://api.github.com/users/{username}");
-    let mut req = Request::get(&url)
-        .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "git2page-wasm");
+#[wasm_bindgen]
+pub async fn fetch_repos_only(payload: JsValue) -> Result<JsValue, JsValue> {
+    let input: FetchReposInput = serde_wasm_bindgen::from_value(payload)
+        .map_err(|e| JsValue::from_str(&format!("Invalid payload: {e}")))?;
 
-    if !token.trim().is_empty() {
-        req = req.header("Authorization", &format!("Bearer {}", token.trim()));
+    if input.github_username.trim().is_empty() {
+        return Err(JsValue::from_str("GitHub username is required"));
     }
 
-    let resp = req
+    let github = GitHubApi::new(&input.github_api_base, &input.github_token, input.extra_headers);
+    let repos = fetch_repos(&github, &input.github_username).await?;
+
+    serde_wasm_bindgen::to_value(&repos)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {e}")))
+}
+
+async fn fetch_github_user(github: &GitHubApi, username: &str) -> Result<GitHubUser, JsValue> {
+    let resp = github
+        .get(&format!("/users/{username}"))
         .send()
         .await
         .map_err(|e| JsValue::from_str(&format!("GitHub user request failed: {e}")))?;
@@ -189,20 +327,9 @@ async fn fetch_github_user(username: &str, token: &str) -> Result<GitHubUser, Js
         .map_err(|e| JsValue::from_str(&format!("GitHub user parse error: {e}")))
 }
 
-async fn fetch_repos(username: &str, token: &str) -> Result<Vec<GitHubRepo>, JsValue> {
-    let url = format!(
-        "https://api.github.com/users/{username}/repos?per_page=100&sort=updated"
-    );
-
-    let mut req = Request::get(&url)
-        .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "git2page-wasm");
-
-    if !token.trim().is_empty() {
-        req = req.header("Authorization", &format!("Bearer {}", token.trim()));
-    }
-
-    let resp = req
+async fn fetch_repos(github: &GitHubApi, username: &str) -> Result<Vec<GitHubRepo>, JsValue> {
+    let resp = github
+        .get(&format!("/users/{username}/repos?per_page=100&sort=updated"))
         .send()
         .await
         .map_err(|e| JsValue::from_str(&format!("GitHub repos request failed: {e}")))?;
@@ -222,87 +349,398 @@ async fn fetch_repos(username: &str, token: &str) -> Result<Vec<GitHubRepo>, JsV
         .map_err(|e| JsValue::from_str(&format!("GitHub repos parse error: {e}")))?;
 
     repos.retain(|r| !r.fork);
-    repos.sort_by(|a, b| b.stargazers_count.cmp(&a.stargazers_count));
+    for repo in repos.iter_mut() {
+        repo.homepage = repo.homepage.take().filter(|h| !h.trim().is_empty());
+    }
+    repos.sort_by_key(|r| std::cmp::Reverse(r.stargazers_count));
     repos.truncate(30);
 
     Ok(repos)
 }
 
-fn build_prompt(username: &str, repos: &[GitHubRepo], language: &str) -> String {
-    let mut repo_lines = String::new();
-    for r in repos {
-        let line = format!(
-            "- {} | lang: {} | stars: {} | forks: {} | topics: {} | desc: {}\n",
-            r.name,
-            r.language.clone().unwrap_or_else(|| "unknown".to_string()),
-            r.stargazers_count,
-            r.forks_count,
-            if r.topics.is_empty() {
-                "none".to_string()
-            } else {
-                r.topics.join(", ")
-            },
-            r.description.clone().unwrap_or_else(|| "".to_string())
-        );
-        repo_lines.push_str(&line);
-    }
+/// The repo's README under whatever name it has, in one call through GitHub's `/readme`.
+async fn fetch_readme(github: &GitHubApi, username: &str, repo: &str) -> Result<String, JsValue> {
+    fetch_contents(github, username, repo, &format!("/repos/{username}/{repo}/readme"), "README").await
+}
 
-    format!(
-        "You are a senior software analyst and branding expert. Return ONLY valid JSON in {language}.\n\nUser: {username}\nRepositories:\n{repo_lines}\n\nReturn this JSON shape:\n{{\n  \"hero_title\": \"...\",\n  \"bio\": \"...\",\n  \"projects\": [\n    {{\n      \"name\": \"repo-name\",\n      \"problem_solved\": \"...\",\n      \"detailed_description\": \"...\",\n      \"use_cases\": [\"...\"],\n      \"tech_stack\": [\"...\"]\n    }}\n  ]\n}}\n\nRules:\n- Include every listed repository in projects.\n- Match each project.name exactly to repository name.\n- Keep descriptions concise and factual."
-    )
+async fn fetch_file_content(
+    github: &GitHubApi,
+    username: &str,
+    repo: &str,
+    path: &str,
+) -> Result<String, JsValue> {
+    fetch_contents(github, username, repo, &format!("/repos/{username}/{repo}/contents/{path}"), path).await
 }
 
-fn detect_api_mode(api_url: &str) -> (&str, String) {
-    let base_url = api_url.trim_end_matches('/');
+/// Fetches and decodes one contents API file; `path` only names it in errors.
+async fn fetch_contents(
+    github: &GitHubApi,
+    username: &str,
+    repo: &str,
+    url: &str,
+    path: &str,
+) -> Result<String, JsValue> {
+    let resp = github
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("GitHub contents request failed: {e}")))?;
 
-    if base_url.ends_with("/chat/completions") {
-        return ("openai", base_url.to_string());
+    if !resp.ok() {
+        return Err(JsValue::from_str(&format!(
+            "File not found: {path} in {username}/{repo}"
+        )));
     }
-    if base_url.ends_with("/api/chat") {
-        return ("ollama", base_url.to_string());
+
+    let content = resp
+        .json::<GitHubContent>()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("GitHub contents parse error: {e}")))?;
+
+    match (content.content, content.encoding) {
+        (Some(encoded), Some(enc)) if enc == "base64" => {
+            let cleaned: String = encoded.chars().filter(|c| !c.is_whitespace()).collect();
+            base64_decode(&cleaned)
+        }
+        _ => Err(JsValue::from_str(&format!(
+            "Unexpected encoding for {username}/{repo}/{path}"
+        ))),
+    }
+}
+
+async fn fetch_dir_files(
+    github: &GitHubApi,
+    username: &str,
+    repo: &str,
+    dir: &str,
+) -> Result<Vec<String>, JsValue> {
+    let resp = github
+        .get(&format!("/repos/{username}/{repo}/contents/{dir}"))
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("GitHub contents request failed: {e}")))?;
+
+    if !resp.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Failed to list repo contents ({})",
+            resp.status()
+        )));
     }
-    if base_url.ends_with("/api/generate") {
-        return ("ollama", base_url.replace("/api/generate", "/api/chat"));
+
+    let items = resp
+        .json::<Vec<serde_json::Value>>()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("GitHub contents parse error: {e}")))?;
+
+    let prefix = if dir.is_empty() {
+        String::new()
+    } else {
+        format!("{dir}/")
+    };
+
+    Ok(items
+        .iter()
+        .filter(|item| item.get("type").and_then(|t| t.as_str()) == Some("file"))
+        .filter_map(|item| item.get("name").and_then(|n| n.as_str()))
+        .map(|name| format!("{prefix}{name}"))
+        .collect())
+}
+
+/// Placeholders for fields the LLM left out, per output language: (language, description, hero title, bio).
+/// `{names}` is the analyzed usernames, `{handles}` the same as @mentions. Mirrors the server's table.
+const FALLBACK_TEXTS: &[(&str, &str, &str, &str)] = &[
+    (
+        "English",
+        "No description available.",
+        "{names} — GitHub Portfolio",
+        "An AI-curated project portfolio for {handles}",
+    ),
+    (
+        "Turkish",
+        "Açıklama mevcut değil.",
+        "{names} — GitHub Portfolyosu",
+        "{handles} için yapay zekâ ile hazırlanmış proje portfolyosu",
+    ),
+    (
+        "German",
+        "Keine Beschreibung verfügbar.",
+        "{names} — GitHub-Portfolio",
+        "Ein KI-kuratiertes Projektportfolio von {handles}",
+    ),
+    (
+        "French",
+        "Aucune description disponible.",
+        "{names} — Portfolio GitHub",
+        "Un portfolio de projets sélectionné par IA pour {handles}",
+    ),
+    (
+        "Spanish",
+        "No hay descripción disponible.",
+        "{names} — Portafolio de GitHub",
+        "Un portafolio de proyectos seleccionado por IA para {handles}",
+    ),
+];
+
+/// Fallback texts for `language`, English when the table doesn't have it.
+fn fallback_texts(language: &str) -> (&'static str, &'static str, &'static str) {
+    let (_, description, hero, bio) = FALLBACK_TEXTS
+        .iter()
+        .find(|(lang, ..)| lang.eq_ignore_ascii_case(language.trim()))
+        .unwrap_or(&FALLBACK_TEXTS[0]);
+    (description, hero, bio)
+}
+
+/// SPDX id, or `None` for a missing license or GitHub's "NOASSERTION" placeholder.
+fn repo_license(repo: &GitHubRepo) -> Option<String> {
+    repo.license
+        .as_ref()
+        .and_then(|l| l.spdx_id.clone())
+        .filter(|id| !id.trim().is_empty() && id != "NOASSERTION")
+}
+
+fn is_source_file(name: &str) -> bool {
+    let ext_list = [
+        ".py", ".js", ".ts", ".rs", ".go", ".java", ".rb", ".php", ".cs", ".swift", ".kt", ".dart",
+        ".c", ".cpp", ".h", ".vue", ".svelte", ".jsx", ".tsx", ".lua", ".sh", ".pl", ".zig", ".ex",
+        ".exs", ".hs", ".scala", ".clj", ".ml", ".erl", ".nim", ".jl",
+    ];
+    let lower = name.to_lowercase();
+    ext_list.iter().any(|ext| lower.ends_with(ext))
+}
+
+fn is_main_file(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    let main_names = [
+        "main.", "app.", "index.", "server.", "program.", "__main__.", "mod.", "lib.", "init.",
+        "cli.", "run.", "start.", "bot.",
+    ];
+    main_names.iter().any(|m| lower.contains(m))
+}
+
+fn base64_decode(input: &str) -> Result<String, JsValue> {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut buf: Vec<u8> = Vec::new();
+    let mut bits: u32 = 0;
+    let mut bit_count: u32 = 0;
+
+    for &byte in input.as_bytes() {
+        if byte == b'=' {
+            break;
+        }
+        let val = match TABLE.iter().position(|&b| b == byte) {
+            Some(v) => v as u32,
+            None => continue,
+        };
+        bits = (bits << 6) | val;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            buf.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
     }
 
-    if base_url.len() > 3 {
-        let last3 = &base_url[base_url.len() - 3..];
-        if last3.starts_with("/v")
-            && last3
-                .chars()
-                .last()
-                .map(|c| c.is_ascii_digit())
-                .unwrap_or(false)
-        {
-            return ("openai", format!("{base_url}/chat/completions"));
+    String::from_utf8(buf).map_err(|e| JsValue::from_str(&format!("UTF-8 decode error: {e}")))
+}
+
+/// Manifests looked for in a repo's root listing, in order of preference. Mirrors the server's defaults.
+const MANIFEST_FILES: &[&str] = &[
+    "Cargo.toml", "package.json", "pyproject.toml", "go.mod", "requirements.txt", "setup.py",
+    "build.gradle", "pom.xml", "build.sbt", "deno.json", "mix.exs", "build.zig", "stack.yaml",
+    "composer.json", "Gemfile", "pubspec.yaml", "Package.swift", "CMakeLists.txt", "Dockerfile",
+];
+
+async fn gather_repo_context(github: &GitHubApi, username: &str, repos: &[GitHubRepo]) -> Vec<String> {
+    let mut contexts = Vec::new();
+    let repo_count = repos.len();
+    let max_readme_chars: usize = if repo_count > 15 { 600 } else { 1000 };
+    let max_source_chars: usize = if repo_count > 15 { 800 } else { 1200 };
+    let max_manifest_chars: usize = 300;
+
+    for repo in repos {
+        let mut ctx = format!(
+            "Repo: {} | Stars: {} | Forks: {} | Language: {} | Description: {}",
+            repo.name,
+            repo.stargazers_count,
+            repo.forks_count,
+            repo.language.as_deref().unwrap_or("N/A"),
+            repo.description.as_deref().unwrap_or("N/A")
+        );
+
+        if !repo.topics.is_empty() {
+            ctx.push_str(&format!(" | Topics: {}", repo.topics.join(", ")));
+        }
+
+        if let Some(homepage) = &repo.homepage {
+            ctx.push_str(&format!(" | Homepage: {homepage}"));
+        }
+
+        if let Some(license) = repo_license(repo) {
+            ctx.push_str(&format!(" | License: {license}"));
+        }
+
+        // Unauthenticated browsers get 60 GitHub calls an hour, so every lookup here counts
+        let mut has_readme = false;
+        if let Ok(readme) = fetch_readme(github, username, &repo.name).await {
+            let truncated: String = readme.chars().take(max_readme_chars).collect();
+            ctx.push_str(&format!("\nREADME (truncated):\n{truncated}"));
+            has_readme = true;
+        }
+
+        // One root listing tells which manifests exist; only the first one present is fetched
+        let root_files = fetch_dir_files(github, username, &repo.name, "").await.unwrap_or_default();
+        if let Some(manifest) = MANIFEST_FILES.iter().find(|m| root_files.iter().any(|f| f == *m)) {
+            if let Ok(content) = fetch_file_content(github, username, &repo.name, manifest).await {
+                let truncated: String = content.chars().take(max_manifest_chars).collect();
+                ctx.push_str(&format!("\n{manifest} (truncated):\n{truncated}"));
+            }
+        }
+
+        if !has_readme {
+            let mut found_source = false;
+
+            let mut all_files = root_files;
+            if let Ok(src_files) = fetch_dir_files(github, username, &repo.name, "src").await {
+                all_files.extend(src_files);
+            }
+
+            if !all_files.is_empty() {
+                let file_list = all_files.iter().take(20).cloned().collect::<Vec<_>>().join(", ");
+                ctx.push_str(&format!("\nFILE STRUCTURE: [{file_list}]"));
+
+                let main_sources: Vec<&String> = all_files
+                    .iter()
+                    .filter(|f| is_source_file(f) && is_main_file(f))
+                    .collect();
+                let any_sources: Vec<&String> =
+                    all_files.iter().filter(|f| is_source_file(f)).collect();
+                let target_files = if !main_sources.is_empty() {
+                    main_sources
+                } else {
+                    any_sources
+                };
+
+                for file_path in target_files.iter().take(2) {
+                    if let Ok(content) =
+                        fetch_file_content(github, username, &repo.name, file_path).await
+                    {
+                        let truncated: String = content.chars().take(max_source_chars).collect();
+                        ctx.push_str(&format!("\nSOURCE CODE ({file_path}):\n{truncated}"));
+                        found_source = true;
+                    }
+                }
+            }
+
+            if !found_source {
+                ctx.push_str("\n[No README or source files found — analyze from repo name, language, and description]");
+            }
         }
+
+        contexts.push(ctx);
     }
 
-    if base_url.ends_with("/api") {
-        return ("ollama", format!("{base_url}/chat"));
+    contexts
+}
+
+fn build_prompt(username: &str, contexts: &[String], language: &str, repo_names: &[String]) -> String {
+    let repo_data = contexts.join("\n\n---\n\n");
+    let names_list = repo_names.join(", ");
+
+    format!(
+        "You are a senior software analyst and branding expert. Return ONLY valid JSON in {language}.\n\nUser: {username}\nRepository Data:\n{repo_data}\n\nReturn this JSON shape:\n{{\n  \"hero_title\": \"...\",\n  \"bio\": \"...\",\n  \"projects\": [\n    {{\n      \"name\": \"repo-name\",\n      \"problem_solved\": \"...\",\n      \"detailed_description\": \"...\",\n      \"use_cases\": [\"...\"],\n      \"tech_stack\": [\"...\"]\n    }}\n  ]\n}}\n\nRules:\n- Include every listed repository in projects: [{names_list}]\n- Match each project.name exactly to repository name.\n- If a project has SOURCE CODE, use it to determine what the project does.\n- If a project has no README, infer its purpose from code, dependencies, and metadata.\n- Keep descriptions concise and factual."
+    )
+}
+
+fn build_batch_prompt(contexts: &[String], language: &str, repo_names: &[String]) -> String {
+    let repo_data = contexts.join("\n\n---\n\n");
+    let names_list = repo_names.join(", ");
+
+    format!(
+        "You are a senior software analyst. Return ONLY valid JSON in {language}.\n\nRepository Data:\n{repo_data}\n\nReturn this JSON shape:\n{{\n  \"projects\": [\n    {{\n      \"name\": \"repo-name\",\n      \"problem_solved\": \"...\",\n      \"detailed_description\": \"...\",\n      \"use_cases\": [\"...\"],\n      \"tech_stack\": [\"...\"]\n    }}\n  ]\n}}\n\nRules:\n- Include every listed repository in projects: [{names_list}]\n- Match each project.name exactly to repository name.\n- If a project has SOURCE CODE, use it to determine what the project does.\n- If a project has no README, infer its purpose from code, dependencies, and metadata.\n- Keep descriptions concise and factual."
+    )
+}
+
+/// Canonical base of `api_url` plus its query: scheme and host lowercased, duplicate and
+/// trailing slashes dropped, fragment removed. Mirrors the server's `normalize_api_url`.
+fn normalize_api_url(api_url: &str) -> (String, Option<String>) {
+    let url = api_url.trim().split('#').next().unwrap_or("");
+    let (url, query) = match url.split_once('?') {
+        Some((url, query)) => (url, Some(query.to_string()).filter(|q| !q.is_empty())),
+        None => (url, None),
+    };
+    let (scheme, rest) = match url.split_once("://") {
+        Some((scheme, rest)) => (format!("{}://", scheme.to_ascii_lowercase()), rest),
+        None => (String::new(), url),
+    };
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let authority = match authority.rsplit_once('@') {
+        Some((userinfo, host)) => format!("{userinfo}@{}", host.to_ascii_lowercase()),
+        None => authority.to_ascii_lowercase(),
+    };
+
+    let mut base = scheme + &authority;
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        base.push('/');
+        base.push_str(segment);
     }
+    (base, query)
+}
 
-    if base_url.contains(":11434") || base_url.contains("ollama") {
-        return ("ollama", format!("{base_url}/api/chat"));
+fn detect_api_mode(api_url: &str) -> (&'static str, String) {
+    let (base_url, query) = normalize_api_url(api_url);
+    let path = base_url.split_once("://").map_or(base_url.as_str(), |(_, rest)| rest);
+    let last_segment = path.split_once('/').and_then(|(_, p)| p.rsplit('/').next()).unwrap_or("");
+    let is_version = last_segment
+        .strip_prefix('v')
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()));
+
+    let (mode, endpoint) = if base_url.ends_with("/chat/completions") {
+        ("openai", base_url.clone())
+    } else if base_url.ends_with("/api/chat") {
+        ("ollama", base_url.clone())
+    } else if let Some(prefix) = base_url.strip_suffix("/api/generate") {
+        ("ollama", format!("{prefix}/api/chat"))
+    } else if is_version || base_url.ends_with("/openai") {
+        ("openai", format!("{base_url}/chat/completions"))
+    } else if base_url.ends_with("/api") {
+        ("ollama", format!("{base_url}/chat"))
+    } else if base_url.contains(":11434") || base_url.contains("ollama") {
+        ("ollama", format!("{base_url}/api/chat"))
+    } else {
+        ("openai", format!("{base_url}/v1/chat/completions"))
+    };
+
+    match query {
+        Some(query) => (mode, format!("{endpoint}?{query}")),
+        None => (mode, endpoint),
     }
+}
+
+/// OpenAI reasoning model families; they take a `developer` message instead of `system` and
+/// reject `temperature`.
+const REASONING_MODEL_PREFIXES: &[&str] = &["o1", "o3", "o4"];
 
-    ("openai", format!("{base_url}/v1/chat/completions"))
+fn is_reasoning_model(model: &str) -> bool {
+    let name = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+    REASONING_MODEL_PREFIXES
+        .iter()
+        .any(|p| name == *p || name.strip_prefix(p).is_some_and(|rest| rest.starts_with('-')))
 }
 
-async fn call_llm(
+async fn call_llm<T: DeserializeOwned>(
     api_url: &str,
     api_key: &str,
     model: &str,
     prompt: &str,
     language: &str,
-) -> Result<LlmResponse, JsValue> {
+) -> Result<T, JsValue> {
     let (mode, endpoint) = detect_api_mode(api_url);
 
     let system_msg = format!(
         "You are a senior software analyst and branding expert. Respond ONLY with valid JSON. All text content must be in {language}."
     );
 
-    let body = json!({
+    let mut body = json!({
         "model": model,
         "messages": [
             { "role": "system", "content": system_msg },
@@ -311,6 +749,12 @@ async fn call_llm(
         "temperature": 0.7,
         "stream": false
     });
+    if mode != "ollama" && is_reasoning_model(model) {
+        body["messages"][0]["role"] = json!("developer");
+        if let Some(fields) = body.as_object_mut() {
+            fields.remove("temperature");
+        }
+    }
 
     let mut req = Request::post(&endpoint).header("Content-Type", "application/json");
     if !api_key.trim().is_empty() {
@@ -345,13 +789,25 @@ async fn call_llm(
             .ok_or_else(|| JsValue::from_str("Unexpected Ollama response format"))?
             .to_string()
     } else {
-        val.get("choices")
+        let message = val
+            .get("choices")
             .and_then(|c| c.get(0))
-            .and_then(|c| c.get("message"))
-            .and_then(|m| m.get("content"))
-            .and_then(|c| c.as_str())
-            .ok_or_else(|| JsValue::from_str("Unexpected OpenAI response format"))?
-            .to_string()
+            .and_then(|c| c.get("message"));
+        let content = message.and_then(|m| m.get("content")).and_then(|c| c.as_str());
+        // Function-calling gateways return null content and the JSON as tool call arguments
+        let arguments = message
+            .and_then(|m| m.get("tool_calls"))
+            .and_then(|t| t.get(0))
+            .and_then(|t| t.get("function"))
+            .and_then(|f| f.get("arguments"))
+            .and_then(|a| a.as_str());
+        match content {
+            Some(c) if !c.trim().is_empty() => c,
+            _ => arguments
+                .or(content)
+                .ok_or_else(|| JsValue::from_str("Unexpected OpenAI response format"))?,
+        }
+        .to_string()
     };
 
     let cleaned = content
@@ -362,6 +818,10 @@ async fn call_llm(
         .trim()
         .to_string();
 
-    serde_json::from_str::<LlmResponse>(&cleaned)
+    if cleaned.is_empty() {
+        return Err(JsValue::from_str("LLM returned empty response"));
+    }
+
+    serde_json::from_str::<T>(&cleaned)
         .map_err(|e| JsValue::from_str(&format!("Failed to parse LLM JSON: {e}")))
 }